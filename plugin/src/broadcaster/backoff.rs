@@ -0,0 +1,68 @@
+use {rand::Rng, std::time::Duration};
+
+/// Exponential backoff with jitter for connection-oriented transports:
+/// `delay = min(base * 2^attempt, max_delay)`, randomized by up to 20% so
+/// many reconnecting clients don't retry in lockstep.
+#[derive(Debug, Clone)]
+pub(crate) struct Backoff {
+    base: Duration,
+    max: Duration,
+    attempt: u32,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max,
+            attempt: 0,
+        }
+    }
+
+    /// Returns the next delay to wait before retrying and advances the
+    /// attempt counter.
+    pub fn next_delay(&mut self) -> Duration {
+        let scaled = self.base.as_millis().saturating_mul(1u128 << self.attempt.min(32));
+        let delay = Duration::from_millis(scaled.min(self.max.as_millis()) as u64);
+        self.attempt = self.attempt.saturating_add(1);
+        jitter(delay)
+    }
+
+    /// Resets the attempt counter after a successful send.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+fn jitter(delay: Duration) -> Duration {
+    let jitter_range = delay.as_millis() as f64 * 0.2;
+    let offset = rand::thread_rng().gen_range(-jitter_range..=jitter_range);
+    Duration::from_millis((delay.as_millis() as f64 + offset).max(0.0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_until_capped_at_max() {
+        let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(1));
+        let delays: Vec<_> = (0..8).map(|_| backoff.next_delay()).collect();
+
+        // Jitter can move any single delay by +/-20%, but the trend must still
+        // climb towards `max` and never exceed it (plus jitter headroom).
+        assert!(delays.last().unwrap() <= &Duration::from_secs(1).mul_f64(1.21));
+        assert!(delays[0] < delays[5]);
+    }
+
+    #[test]
+    fn test_backoff_reset_restarts_from_base() {
+        let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(10));
+        backoff.next_delay();
+        backoff.next_delay();
+        backoff.reset();
+
+        let delay = backoff.next_delay();
+        assert!(delay <= Duration::from_millis(120));
+    }
+}
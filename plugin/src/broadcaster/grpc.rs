@@ -0,0 +1,198 @@
+use {
+    super::Transport,
+    crate::message::SlotMessage,
+    proto::{
+        slot_updates_server::{SlotUpdates, SlotUpdatesServer},
+        stream_message::Payload,
+        Ping, SlotUpdate, StreamMessage, SubscribeRequest,
+    },
+    std::{
+        net::SocketAddr,
+        pin::Pin,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc,
+        },
+        time::Duration,
+    },
+    tokio::{
+        sync::broadcast,
+        task::JoinHandle,
+        time::interval,
+    },
+    tokio_stream::{
+        wrappers::{errors::BroadcastStreamRecvError, BroadcastStream, IntervalStream},
+        Stream, StreamExt,
+    },
+    tokio_util::sync::CancellationToken,
+    tonic::{transport::Server, Request, Response, Status},
+};
+
+pub mod proto {
+    tonic::include_proto!("slot_updates");
+}
+
+/// Server-streamed gRPC alternative to fire-and-forget UDP: clients
+/// `subscribe` and each gets its own stream fanned out from a shared
+/// broadcast channel.
+pub(crate) struct GrpcTransport {
+    broadcast_tx: broadcast::Sender<SlotMessage>,
+    last_slot: Arc<AtomicU64>,
+    server_cancel: CancellationToken,
+    server_handle: JoinHandle<Result<(), tonic::transport::Error>>,
+}
+
+impl GrpcTransport {
+    pub fn new(
+        bind_address: SocketAddr,
+        broadcast_capacity: usize,
+        ping_interval_secs: u64,
+    ) -> anyhow::Result<Self> {
+        let (broadcast_tx, _) = broadcast::channel(broadcast_capacity);
+        let last_slot = Arc::new(AtomicU64::new(0));
+        let server_cancel = CancellationToken::new();
+
+        let service = SlotUpdatesService {
+            broadcast_tx: broadcast_tx.clone(),
+            last_slot: last_slot.clone(),
+            ping_interval: Duration::from_secs(ping_interval_secs),
+        };
+
+        let shutdown_signal = server_cancel.clone();
+        let server_handle = tokio::spawn(async move {
+            Server::builder()
+                .add_service(SlotUpdatesServer::new(service))
+                .serve_with_shutdown(bind_address, shutdown_signal.cancelled())
+                .await
+        });
+
+        Ok(Self {
+            broadcast_tx,
+            last_slot,
+            server_cancel,
+            server_handle,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for GrpcTransport {
+    async fn send(&mut self, message: &SlotMessage) {
+        self.last_slot.fetch_max(message.slot, Ordering::Relaxed);
+        let _ = self.broadcast_tx.send(message.clone());
+    }
+
+    async fn shutdown(self: Box<Self>) {
+        self.server_cancel.cancel();
+        match self.server_handle.await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => log::error!("gRPC server exited with error: {e}"),
+            Err(e) => log::error!("gRPC server task panicked: {e}"),
+        }
+    }
+}
+
+/// Implements the `SlotUpdates` gRPC service: each `subscribe` call gets its
+/// own stream fanned out from the shared broadcast channel.
+struct SlotUpdatesService {
+    broadcast_tx: broadcast::Sender<SlotMessage>,
+    last_slot: Arc<AtomicU64>,
+    ping_interval: Duration,
+}
+
+#[tonic::async_trait]
+impl SlotUpdates for SlotUpdatesService {
+    type SubscribeStream = Pin<Box<dyn Stream<Item = Result<StreamMessage, Status>> + Send>>;
+
+    async fn subscribe(
+        &self,
+        _request: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let resume_from_slot = self.last_slot.load(Ordering::Relaxed);
+        log::info!("new gRPC subscriber connected, resuming from slot {resume_from_slot}");
+
+        let last_slot = self.last_slot.clone();
+        let updates = BroadcastStream::new(self.broadcast_tx.subscribe()).filter_map(
+            move |message| match message {
+                Ok(message) => Some(Ok(slot_update_message(&message))),
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    log::warn!("gRPC subscriber lagged behind, dropped {skipped} messages");
+                    None
+                }
+            },
+        );
+
+        let pings = IntervalStream::new(interval(self.ping_interval))
+            .map(move |_| Ok(ping_message(last_slot.load(Ordering::Relaxed))));
+
+        let stream = updates.merge(pings);
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+fn slot_update_message(message: &SlotMessage) -> StreamMessage {
+    StreamMessage {
+        payload: Some(Payload::SlotUpdate(SlotUpdate {
+            slot: message.slot,
+            parent: message.parent,
+            status: message.status.as_str().to_string(),
+            dead_error: message.dead_error.clone(),
+            created_at: message.created_at,
+        })),
+    }
+}
+
+fn ping_message(last_slot: u64) -> StreamMessage {
+    StreamMessage {
+        payload: Some(Payload::Ping(Ping { last_slot })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        agave_geyser_plugin_interface::geyser_plugin_interface::SlotStatus,
+        solana_time_utils::timestamp,
+        tokio::time::timeout,
+    };
+
+    #[tokio::test]
+    async fn test_grpc_service_fans_out_messages_to_subscribers() {
+        let (broadcast_tx, _) = broadcast::channel(16);
+        let service = SlotUpdatesService {
+            broadcast_tx: broadcast_tx.clone(),
+            last_slot: Arc::new(AtomicU64::new(7)),
+            ping_interval: Duration::from_secs(3600),
+        };
+
+        let mut stream = service
+            .subscribe(Request::new(SubscribeRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let msg = SlotMessage {
+            slot: 42,
+            status: SlotStatus::Rooted,
+            parent: Some(41),
+            dead_error: None,
+            created_at: timestamp(),
+        };
+        broadcast_tx.send(msg).unwrap();
+
+        let received = timeout(Duration::from_secs(1), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        match received.payload {
+            Some(Payload::SlotUpdate(update)) => {
+                assert_eq!(update.slot, 42);
+                assert_eq!(update.parent, Some(41));
+            }
+            other => panic!("expected a SlotUpdate payload, got {other:?}"),
+        }
+    }
+}
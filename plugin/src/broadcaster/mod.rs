@@ -0,0 +1,188 @@
+use {
+    crate::{
+        config::{BroadcasterConfig, TransportConfig},
+        message::SlotMessage,
+    },
+    tokio::{
+        select,
+        sync::mpsc::{self, Receiver, Sender},
+        task::JoinHandle,
+    },
+    tokio_util::sync::CancellationToken,
+};
+
+mod backoff;
+mod grpc;
+mod mqtt;
+mod quic;
+mod ring_buffer;
+mod udp;
+
+/// Common interface every broadcaster transport implements, so `Broadcaster`
+/// can drive UDP, gRPC, QUIC, and MQTT through the same `select!`/cancellation
+/// loop instead of duplicating it per transport. Implementations are expected
+/// to handle their own reconnection and never propagate transient network
+/// errors back up to the loop.
+#[async_trait::async_trait]
+pub(crate) trait Transport: Send {
+    /// For broadcast-based transports (gRPC, QUIC) having no subscribers is
+    /// not an error: the message is simply dropped.
+    async fn send(&mut self, message: &SlotMessage);
+    async fn shutdown(self: Box<Self>);
+}
+
+#[derive(Debug)]
+pub(crate) struct Broadcaster {
+    handle: JoinHandle<()>,
+    cancel: CancellationToken,
+}
+
+impl Broadcaster {
+    pub async fn run(
+        config: BroadcasterConfig,
+        cancel: CancellationToken,
+    ) -> anyhow::Result<(Sender<SlotMessage>, Broadcaster)> {
+        let (sender, receiver) = mpsc::channel(config.channel_capacity);
+        let cancel_clone = cancel.clone();
+
+        let transport = build_transport(config).await?;
+        let handle = tokio::spawn(run_loop(transport, receiver, cancel));
+
+        Ok((
+            sender,
+            Broadcaster {
+                handle,
+                cancel: cancel_clone,
+            },
+        ))
+    }
+
+    pub async fn shutdown(self) -> anyhow::Result<()> {
+        self.cancel.cancel();
+        self.handle.await?;
+        Ok(())
+    }
+}
+
+async fn build_transport(config: BroadcasterConfig) -> anyhow::Result<Box<dyn Transport>> {
+    let format = config.format;
+    match config.transport {
+        TransportConfig::Udp {
+            target_addresses,
+            pacing,
+        } => Ok(Box::new(
+            udp::UdpTransport::new(config.bind_address, target_addresses, pacing, format).await?,
+        )),
+        TransportConfig::Grpc {
+            broadcast_capacity,
+            ping_interval_secs,
+        } => Ok(Box::new(grpc::GrpcTransport::new(
+            config.bind_address,
+            broadcast_capacity,
+            ping_interval_secs,
+        )?)),
+        TransportConfig::Quic {
+            cert_path,
+            key_path,
+            max_concurrent_streams,
+            idle_timeout_secs,
+        } => Ok(Box::new(quic::QuicTransport::new(
+            config.bind_address,
+            cert_path,
+            key_path,
+            max_concurrent_streams,
+            idle_timeout_secs,
+            format,
+        )?)),
+        TransportConfig::Mqtt {
+            broker_address,
+            topic_prefix,
+            qos,
+        } => Ok(Box::new(mqtt::MqttTransport::new(
+            broker_address,
+            topic_prefix,
+            qos,
+            format,
+        )?)),
+    }
+}
+
+async fn run_loop(
+    mut transport: Box<dyn Transport>,
+    mut receiver: Receiver<SlotMessage>,
+    cancel: CancellationToken,
+) {
+    loop {
+        select! {
+            Some(message) = receiver.recv() => {
+                transport.send(&message).await;
+            }
+            _ = cancel.cancelled() => {
+                log::info!("broadcaster service is shutting down");
+                break;
+            }
+        }
+    }
+
+    // Drain whatever is already sitting in the channel before handing off to
+    // the transport's own shutdown, so messages queued right before
+    // cancellation aren't silently lost.
+    while let Ok(message) = receiver.try_recv() {
+        transport.send(&message).await;
+    }
+
+    transport.shutdown().await;
+    log::info!("Broadcaster service has shut down");
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        agave_geyser_plugin_interface::geyser_plugin_interface::SlotStatus,
+        crate::message::WireFormat,
+        solana_time_utils::timestamp,
+        std::time::Duration,
+        tokio::{net::UdpSocket, time::timeout},
+    };
+
+    #[tokio::test]
+    async fn test_broadcaster_sends_udp_messages() {
+        let target_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        let config = BroadcasterConfig {
+            bind_address: "127.0.0.1:0".parse().unwrap(),
+            channel_capacity: 10,
+            transport: TransportConfig::Udp {
+                target_addresses: vec![target_socket.local_addr().unwrap()],
+                pacing: None,
+            },
+            format: WireFormat::Json,
+        };
+
+        let cancel = CancellationToken::new();
+        let (sender, broadcaster) = Broadcaster::run(config, cancel.clone()).await.unwrap();
+
+        let msg = SlotMessage {
+            slot: 1,
+            status: SlotStatus::Completed,
+            parent: None,
+            dead_error: None,
+            created_at: timestamp(),
+        };
+        let expected_msg = msg.clone();
+        sender.try_send(msg).unwrap();
+
+        let mut buf = [0u8; 1024];
+        let received = timeout(Duration::from_secs(1), target_socket.recv_from(&mut buf))
+            .await
+            .unwrap()
+            .unwrap();
+        let data = &buf[..received.0];
+        let actual_msg: SlotMessage = serde_json::from_slice(data).unwrap();
+
+        assert_eq!(actual_msg, expected_msg);
+
+        broadcaster.shutdown().await.unwrap();
+    }
+}
@@ -0,0 +1,182 @@
+use {
+    super::{backoff::Backoff, ring_buffer::RingBuffer, Transport},
+    crate::message::{slot_status_as_str, SlotMessage, WireFormat},
+    rumqttc::{AsyncClient, MqttOptions, Packet, QoS},
+    std::{net::SocketAddr, sync::Arc, time::Duration},
+    tokio::{select, sync::Mutex, task::JoinHandle},
+    tokio_util::sync::CancellationToken,
+};
+
+/// Capacity of rumqttc's internal request channel between `AsyncClient` and
+/// its `EventLoop`.
+const MQTT_EVENT_CHANNEL_CAPACITY: usize = 10;
+
+/// Maximum number of messages held after the MQTT client refused them, until
+/// they are replayed.
+const MQTT_PENDING_CAPACITY: usize = 1024;
+
+/// Publishes each slot update to an MQTT broker under
+/// `<topic_prefix>/<status>`, so subscribers can filter by status with topic
+/// wildcards. Reconnects with exponential backoff. A message whose publish
+/// call fails is kept in a bounded buffer and replayed after the next
+/// `ConnAck`; `publish` only fails once rumqttc's request channel is closed,
+/// so while the broker is unreachable messages queue in that channel and
+/// `send` waits once it is full instead of filling the buffer.
+pub(crate) struct MqttTransport {
+    client: AsyncClient,
+    topic_prefix: String,
+    qos: QoS,
+    format: WireFormat,
+    pending: Arc<Mutex<RingBuffer<SlotMessage>>>,
+    poll_cancel: CancellationToken,
+    poll_handle: JoinHandle<()>,
+}
+
+impl MqttTransport {
+    pub fn new(
+        broker_address: SocketAddr,
+        topic_prefix: String,
+        qos: u8,
+        format: WireFormat,
+    ) -> anyhow::Result<Self> {
+        let qos = mqtt_qos(qos)?;
+        let mut mqtt_options = MqttOptions::new(
+            format!("slot-update-geyser-plugin-{}", std::process::id()),
+            broker_address.ip().to_string(),
+            broker_address.port(),
+        );
+        mqtt_options.set_keep_alive(Duration::from_secs(30));
+        let (client, mut event_loop) = AsyncClient::new(mqtt_options, MQTT_EVENT_CHANNEL_CAPACITY);
+
+        let pending = Arc::new(Mutex::new(RingBuffer::new(MQTT_PENDING_CAPACITY)));
+        let poll_cancel = CancellationToken::new();
+
+        let task_client = client.clone();
+        let task_topic_prefix = topic_prefix.clone();
+        let task_pending = pending.clone();
+        let task_cancel = poll_cancel.clone();
+        let poll_handle = tokio::spawn(async move {
+            let mut backoff = Backoff::new(Duration::from_millis(500), Duration::from_secs(30));
+            loop {
+                select! {
+                    event = event_loop.poll() => {
+                        match event {
+                            Ok(rumqttc::Event::Incoming(Packet::ConnAck(_))) => {
+                                backoff.reset();
+                                replay_pending(&task_client, &task_topic_prefix, qos, format, &task_pending).await;
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                log::warn!("MQTT connection error: {e}");
+                                tokio::time::sleep(backoff.next_delay()).await;
+                            }
+                        }
+                    }
+                    _ = task_cancel.cancelled() => break,
+                }
+            }
+        });
+
+        Ok(Self {
+            client,
+            topic_prefix,
+            qos,
+            format,
+            pending,
+            poll_cancel,
+            poll_handle,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for MqttTransport {
+    async fn send(&mut self, message: &SlotMessage) {
+        if publish_slot_message(&self.client, &self.topic_prefix, self.qos, self.format, message)
+            .await
+            .is_err()
+        {
+            self.pending.lock().await.push(message.clone());
+        }
+    }
+
+    async fn shutdown(self: Box<Self>) {
+        // Drain whatever is still buffered before closing the connection.
+        {
+            let mut pending = self.pending.lock().await;
+            while let Some(message) = pending.pop_front() {
+                let _ = publish_slot_message(
+                    &self.client,
+                    &self.topic_prefix,
+                    self.qos,
+                    self.format,
+                    &message,
+                )
+                .await;
+            }
+        }
+
+        self.poll_cancel.cancel();
+        if let Err(e) = self.client.disconnect().await {
+            log::warn!("failed to cleanly disconnect from MQTT broker: {e}");
+        }
+        if let Err(e) = self.poll_handle.await {
+            log::error!("MQTT poll task panicked: {e}");
+        }
+    }
+}
+
+async fn replay_pending(
+    client: &AsyncClient,
+    topic_prefix: &str,
+    qos: QoS,
+    format: WireFormat,
+    pending: &Mutex<RingBuffer<SlotMessage>>,
+) {
+    let mut pending = pending.lock().await;
+    while let Some(message) = pending.pop_front() {
+        if publish_slot_message(client, topic_prefix, qos, format, &message).await.is_err() {
+            pending.push(message);
+            break;
+        }
+    }
+}
+
+async fn publish_slot_message(
+    client: &AsyncClient,
+    topic_prefix: &str,
+    qos: QoS,
+    format: WireFormat,
+    message: &SlotMessage,
+) -> Result<(), rumqttc::ClientError> {
+    let Ok(data) = message.encode(format) else {
+        log::error!("failed to serialize message: {:?}", message);
+        return Ok(());
+    };
+    let topic = format!("{topic_prefix}/{}", slot_status_as_str::as_str(&message.status));
+    client.publish(topic, qos, false, data).await.inspect_err(|e| {
+        log::error!("failed to publish MQTT message: {e}");
+    })
+}
+
+fn mqtt_qos(qos: u8) -> anyhow::Result<QoS> {
+    match qos {
+        0 => Ok(QoS::AtMostOnce),
+        1 => Ok(QoS::AtLeastOnce),
+        2 => Ok(QoS::ExactlyOnce),
+        other => anyhow::bail!("invalid MQTT QoS level: {other}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mqtt_qos_rejects_invalid_levels() {
+        assert!(matches!(mqtt_qos(0), Ok(QoS::AtMostOnce)));
+        assert!(matches!(mqtt_qos(1), Ok(QoS::AtLeastOnce)));
+        assert!(matches!(mqtt_qos(2), Ok(QoS::ExactlyOnce)));
+        assert!(mqtt_qos(3).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use {
+    super::Transport,
+    crate::message::{SlotMessage, WireFormat},
+    quinn::rustls::pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+    std::{net::SocketAddr, sync::Arc, time::Duration},
+    tokio::{select, sync::broadcast, task::JoinHandle},
+    tokio_util::sync::CancellationToken,
+};
+
+/// Capacity of the broadcast channel fanning `SlotMessage`s out to connected
+/// QUIC streams. Unlike the gRPC transport this isn't operator-configurable
+/// since each connection only ever holds a handful of unsent messages.
+const QUIC_BROADCAST_CAPACITY: usize = 1024;
+
+/// QUIC transport: each connected client gets its own unidirectional stream
+/// carrying length-delimited, ordered `SlotMessage`s.
+pub(crate) struct QuicTransport {
+    endpoint: quinn::Endpoint,
+    broadcast_tx: broadcast::Sender<SlotMessage>,
+    accept_cancel: CancellationToken,
+    accept_handle: JoinHandle<()>,
+}
+
+impl QuicTransport {
+    pub fn new(
+        bind_address: SocketAddr,
+        cert_path: Option<String>,
+        key_path: Option<String>,
+        max_concurrent_streams: u32,
+        idle_timeout_secs: u64,
+        format: WireFormat,
+    ) -> anyhow::Result<Self> {
+        let server_config =
+            build_quic_server_config(cert_path, key_path, max_concurrent_streams, idle_timeout_secs)?;
+        let endpoint = quinn::Endpoint::server(server_config, bind_address)?;
+        let (broadcast_tx, _) = broadcast::channel::<SlotMessage>(QUIC_BROADCAST_CAPACITY);
+
+        let accept_cancel = CancellationToken::new();
+        let task_cancel = accept_cancel.clone();
+        let task_broadcast_tx = broadcast_tx.clone();
+        let task_endpoint = endpoint.clone();
+        let accept_handle = tokio::spawn(async move {
+            loop {
+                select! {
+                    incoming = task_endpoint.accept() => {
+                        let Some(incoming) = incoming else { break };
+                        let broadcast_tx = task_broadcast_tx.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = serve_quic_connection(incoming, broadcast_tx, format).await {
+                                log::warn!("QUIC connection ended: {e}");
+                            }
+                        });
+                    }
+                    _ = task_cancel.cancelled() => break,
+                }
+            }
+        });
+
+        Ok(Self {
+            endpoint,
+            broadcast_tx,
+            accept_cancel,
+            accept_handle,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for QuicTransport {
+    async fn send(&mut self, message: &SlotMessage) {
+        let _ = self.broadcast_tx.send(message.clone());
+    }
+
+    async fn shutdown(self: Box<Self>) {
+        self.accept_cancel.cancel();
+        self.endpoint.close(0u32.into(), b"shutting down");
+        if let Err(e) = self.accept_handle.await {
+            log::error!("QUIC accept task panicked: {e}");
+        }
+    }
+}
+
+/// Serves a single QUIC client: opens one unidirectional stream and forwards
+/// every broadcast `SlotMessage` to it, length-delimited so the consumer can
+/// reassemble messages across datagram boundaries.
+async fn serve_quic_connection(
+    incoming: quinn::Incoming,
+    broadcast_tx: broadcast::Sender<SlotMessage>,
+    format: WireFormat,
+) -> anyhow::Result<()> {
+    let connection = incoming.await?;
+    let mut send = connection.open_uni().await?;
+    let mut rx = broadcast_tx.subscribe();
+
+    loop {
+        let message = match rx.recv().await {
+            Ok(message) => message,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                log::warn!("QUIC subscriber lagged behind, dropped {skipped} messages");
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        let data = message.encode(format)?;
+        send.write_all(&(data.len() as u32).to_be_bytes()).await?;
+        send.write_all(&data).await?;
+    }
+
+    send.finish()?;
+    Ok(())
+}
+
+fn build_quic_server_config(
+    cert_path: Option<String>,
+    key_path: Option<String>,
+    max_concurrent_streams: u32,
+    idle_timeout_secs: u64,
+) -> anyhow::Result<quinn::ServerConfig> {
+    let (cert_chain, key) = match (cert_path, key_path) {
+        (Some(cert_path), Some(key_path)) => load_cert_and_key(&cert_path, &key_path)?,
+        _ => generate_self_signed_cert()?,
+    };
+
+    let mut transport_config = quinn::TransportConfig::default();
+    transport_config.max_concurrent_uni_streams(max_concurrent_streams.into());
+    transport_config.max_idle_timeout(Some(Duration::from_secs(idle_timeout_secs).try_into()?));
+
+    let mut server_config = quinn::ServerConfig::with_single_cert(cert_chain, key)?;
+    server_config.transport_config(Arc::new(transport_config));
+    Ok(server_config)
+}
+
+fn load_cert_and_key(
+    cert_path: &str,
+    key_path: &str,
+) -> anyhow::Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)> {
+    let cert_chain = CertificateDer::pem_file_iter(cert_path)?.collect::<Result<Vec<_>, _>>()?;
+    let key = PrivateKeyDer::from_pem_file(key_path)?;
+    Ok((cert_chain, key))
+}
+
+fn generate_self_signed_cert() -> anyhow::Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)> {
+    let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])?;
+    let key = PrivateKeyDer::try_from(cert.signing_key.serialize_der())
+        .map_err(|e| anyhow::anyhow!("failed to build private key: {e}"))?;
+    Ok((vec![cert.cert.der().clone()], key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_quic_server_config_generates_self_signed_cert_when_unset() {
+        build_quic_server_config(None, None, 100, 30).unwrap();
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::VecDeque;
+
+/// Bounded FIFO that evicts the oldest entry once full, used by
+/// connection-oriented transports to retain the most recent messages across
+/// a short reconnect outage.
+#[derive(Debug)]
+pub(crate) struct RingBuffer<T> {
+    capacity: usize,
+    items: VecDeque<T>,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            items: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Pushes `item`, dropping the oldest entry if the buffer is already full.
+    /// Returns the evicted item, if any, so callers can report the loss. A
+    /// zero-capacity buffer evicts `item` itself, holding nothing.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if self.capacity == 0 {
+            return Some(item);
+        }
+        let evicted = if self.items.len() >= self.capacity {
+            self.items.pop_front()
+        } else {
+            None
+        };
+        self.items.push_back(item);
+        evicted
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_evicts_oldest_when_full() {
+        let mut buffer = RingBuffer::new(2);
+        assert_eq!(buffer.push(1), None);
+        assert_eq!(buffer.push(2), None);
+        assert_eq!(buffer.push(3), Some(1));
+
+        assert_eq!(buffer.pop_front(), Some(2));
+        assert_eq!(buffer.pop_front(), Some(3));
+        assert_eq!(buffer.pop_front(), None);
+    }
+
+    #[test]
+    fn test_ring_buffer_is_empty_after_draining() {
+        let mut buffer = RingBuffer::new(4);
+        buffer.push(1);
+        buffer.pop_front();
+
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_ring_buffer_with_zero_capacity_holds_nothing() {
+        let mut buffer = RingBuffer::new(0);
+        assert_eq!(buffer.push(1), Some(1));
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.pop_front(), None);
+    }
+}
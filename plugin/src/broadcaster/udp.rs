@@ -0,0 +1,262 @@
+use {
+    super::{ring_buffer::RingBuffer, Transport},
+    crate::{
+        config::PacingConfig,
+        message::{SlotMessage, WireFormat},
+    },
+    std::{
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc,
+        },
+        time::Duration,
+    },
+    tokio::{
+        net::UdpSocket,
+        sync::Mutex,
+        task::JoinHandle,
+        time::interval,
+    },
+};
+
+/// Fire-and-forget UDP datagrams fanned out to one or more targets. UDP has
+/// no connection to lose, so unlike the MQTT transport this one has no
+/// backoff/reconnect logic.
+pub(crate) struct UdpTransport {
+    socket: Arc<UdpSocket>,
+    target_addresses: Vec<SocketAddr>,
+    format: WireFormat,
+    pacer: Option<Pacer>,
+}
+
+impl UdpTransport {
+    pub async fn new(
+        bind_address: SocketAddr,
+        target_addresses: Vec<SocketAddr>,
+        pacing: Option<PacingConfig>,
+        format: WireFormat,
+    ) -> anyhow::Result<Self> {
+        let Ok(socket) = UdpSocket::bind(bind_address).await else {
+            anyhow::bail!("failed to bind to address {}", bind_address);
+        };
+        let socket = Arc::new(socket);
+        let pacer = pacing
+            .map(|pacing| Pacer::spawn(socket.clone(), target_addresses.clone(), pacing, format));
+
+        Ok(Self {
+            socket,
+            target_addresses,
+            format,
+            pacer,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for UdpTransport {
+    async fn send(&mut self, message: &SlotMessage) {
+        match &self.pacer {
+            Some(pacer) => {
+                if pacer.buffer.lock().await.push(message.clone()).is_some() {
+                    let dropped = pacer.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                    log::warn!("UDP pacing buffer is full, dropped {dropped} messages so far");
+                }
+            }
+            None => send_datagram(&self.socket, &self.target_addresses, message, self.format).await,
+        }
+    }
+
+    async fn shutdown(self: Box<Self>) {
+        if let Some(pacer) = self.pacer {
+            pacer.handle.abort();
+        }
+    }
+}
+
+/// Smooths bursts of outgoing datagrams: messages are buffered and flushed
+/// on a fixed tick, sending at most `max_packets_per_interval` per tick and
+/// carrying the remainder to the next one.
+struct Pacer {
+    buffer: Arc<Mutex<RingBuffer<SlotMessage>>>,
+    /// Running count of messages evicted because the buffer was full, logged
+    /// on every eviction so operators can see when a downstream consumer
+    /// can't keep up.
+    dropped: Arc<AtomicU64>,
+    handle: JoinHandle<()>,
+}
+
+impl Pacer {
+    fn spawn(
+        socket: Arc<UdpSocket>,
+        target_addresses: Vec<SocketAddr>,
+        pacing: PacingConfig,
+        format: WireFormat,
+    ) -> Self {
+        let buffer = Arc::new(Mutex::new(RingBuffer::new(pacing.buffer_capacity)));
+        let task_buffer = buffer.clone();
+
+        let handle = tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_millis(pacing.interval_ms));
+            loop {
+                ticker.tick().await;
+
+                let mut batch = Vec::with_capacity(pacing.max_packets_per_interval);
+                {
+                    let mut buffer = task_buffer.lock().await;
+                    for _ in 0..pacing.max_packets_per_interval {
+                        let Some(message) = buffer.pop_front() else {
+                            break;
+                        };
+                        batch.push(message);
+                    }
+                }
+
+                for message in &batch {
+                    send_datagram(&socket, &target_addresses, message, format).await;
+                }
+            }
+        });
+
+        Self {
+            buffer,
+            dropped: Arc::new(AtomicU64::new(0)),
+            handle,
+        }
+    }
+}
+
+async fn send_datagram(
+    socket: &UdpSocket,
+    target_addresses: &[SocketAddr],
+    message: &SlotMessage,
+    format: WireFormat,
+) {
+    let Ok(data) = message.encode(format) else {
+        log::error!("failed to serialize message: {:?}", message);
+        return;
+    };
+    for target_address in target_addresses {
+        if let Err(e) = socket.send_to(&data, target_address).await {
+            log::error!("failed to send UDP packet to {target_address}: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        agave_geyser_plugin_interface::geyser_plugin_interface::SlotStatus,
+        crate::message::WireFormat,
+        solana_time_utils::timestamp,
+        tokio::time::timeout,
+    };
+
+    fn make_msg(slot: u64) -> SlotMessage {
+        SlotMessage {
+            slot,
+            status: SlotStatus::Rooted,
+            parent: None,
+            dead_error: None,
+            created_at: timestamp(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_udp_transport_sends_to_all_targets() {
+        let target_a = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let target_b = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        let mut transport: Box<dyn Transport> = Box::new(
+            UdpTransport::new(
+                "127.0.0.1:0".parse().unwrap(),
+                vec![target_a.local_addr().unwrap(), target_b.local_addr().unwrap()],
+                None,
+                WireFormat::Json,
+            )
+            .await
+            .unwrap(),
+        );
+
+        let msg = make_msg(1);
+        transport.send(&msg).await;
+
+        for target_socket in [&target_a, &target_b] {
+            let mut buf = [0u8; 1024];
+            let received = timeout(Duration::from_secs(1), target_socket.recv_from(&mut buf))
+                .await
+                .unwrap()
+                .unwrap();
+            let actual_msg: SlotMessage = serde_json::from_slice(&buf[..received.0]).unwrap();
+            assert_eq!(actual_msg, msg);
+        }
+
+        transport.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_udp_transport_paces_messages_across_ticks() {
+        let target_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        let mut transport: Box<dyn Transport> = Box::new(
+            UdpTransport::new(
+                "127.0.0.1:0".parse().unwrap(),
+                vec![target_socket.local_addr().unwrap()],
+                Some(PacingConfig {
+                    max_packets_per_interval: 1,
+                    interval_ms: 20,
+                    buffer_capacity: 4,
+                }),
+                WireFormat::Json,
+            )
+            .await
+            .unwrap(),
+        );
+
+        transport.send(&make_msg(1)).await;
+        transport.send(&make_msg(2)).await;
+
+        let mut received_slots = Vec::new();
+        for _ in 0..2 {
+            let mut buf = [0u8; 1024];
+            let received = timeout(Duration::from_millis(500), target_socket.recv_from(&mut buf))
+                .await
+                .unwrap()
+                .unwrap();
+            let actual_msg: SlotMessage = serde_json::from_slice(&buf[..received.0]).unwrap();
+            received_slots.push(actual_msg.slot);
+        }
+
+        assert_eq!(received_slots, vec![1, 2]);
+
+        transport.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_udp_transport_counts_drops_when_pacing_buffer_is_full() {
+        let target_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        let mut transport = UdpTransport::new(
+            "127.0.0.1:0".parse().unwrap(),
+            vec![target_socket.local_addr().unwrap()],
+            Some(PacingConfig {
+                max_packets_per_interval: 1,
+                interval_ms: 60_000,
+                buffer_capacity: 1,
+            }),
+            WireFormat::Json,
+        )
+        .await
+        .unwrap();
+
+        transport.send(&make_msg(1)).await;
+        transport.send(&make_msg(2)).await;
+        transport.send(&make_msg(3)).await;
+
+        let dropped = transport.pacer.as_ref().unwrap().dropped.load(Ordering::Relaxed);
+        assert_eq!(dropped, 2);
+
+        Box::new(transport).shutdown().await;
+    }
+}
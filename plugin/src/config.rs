@@ -1,4 +1,5 @@
 use {
+    crate::message::WireFormat,
     agave_geyser_plugin_interface::geyser_plugin_interface::GeyserPluginError,
     serde::{Deserialize, Deserializer},
     std::net::SocketAddr,
@@ -83,14 +84,125 @@ impl TokioConfig {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct BroadcasterConfig {
-    /// Address of Grpc service.
+    /// Address the broadcaster binds to (UDP socket, gRPC server or QUIC
+    /// endpoint; not used by `Mqtt`).
     #[serde(deserialize_with = "deserialize_resolvable_socket_addr")]
     pub bind_address: SocketAddr,
-    /// Address of the destination to send messages.
-    #[serde(deserialize_with = "deserialize_resolvable_socket_addr")]
-    pub target_address: SocketAddr,
     /// Capacity of the channel used to communicate with broadcaster task.
     pub channel_capacity: usize,
+    /// Transport used to deliver slot updates to consumers.
+    pub transport: TransportConfig,
+    /// Wire format used to serialize `SlotMessage`s (not used by `Grpc`,
+    /// which always serializes via its protobuf schema).
+    #[serde(default)]
+    pub format: WireFormat,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum TransportConfig {
+    /// Fire-and-forget UDP datagrams fanned out to one or more destinations.
+    Udp {
+        /// Addresses of the destinations to send messages to.
+        #[serde(deserialize_with = "deserialize_resolvable_socket_addrs")]
+        target_addresses: Vec<SocketAddr>,
+        /// Optional batching/pacing of outgoing datagrams. When omitted,
+        /// every message is sent to every target as soon as it arrives.
+        #[serde(default)]
+        pacing: Option<PacingConfig>,
+    },
+    /// Server-streamed gRPC subscription service: clients `subscribe` and
+    /// receive every slot update as it is broadcast.
+    Grpc {
+        /// Capacity of the broadcast channel each subscriber is fanned out from.
+        #[serde(default = "TransportConfig::default_broadcast_capacity")]
+        broadcast_capacity: usize,
+        /// Interval between keepalive `Ping` frames sent to every subscriber.
+        #[serde(
+            default = "TransportConfig::default_ping_interval_secs",
+            deserialize_with = "deserialize_positive_u64"
+        )]
+        ping_interval_secs: u64,
+    },
+    /// QUIC transport: each connected client gets its own unidirectional
+    /// stream carrying length-delimited, ordered `SlotMessage`s.
+    Quic {
+        /// Path to a PEM certificate chain. A self-signed certificate is
+        /// generated on the fly when omitted, which is convenient for local use.
+        #[serde(default)]
+        cert_path: Option<String>,
+        /// Path to the PEM private key matching `cert_path`.
+        #[serde(default)]
+        key_path: Option<String>,
+        /// Maximum number of concurrent unidirectional streams per connection.
+        #[serde(default = "TransportConfig::default_max_concurrent_streams")]
+        max_concurrent_streams: u32,
+        /// Idle timeout after which an unresponsive connection is dropped.
+        #[serde(default = "TransportConfig::default_idle_timeout_secs")]
+        idle_timeout_secs: u64,
+    },
+    /// Publishes each slot update to an MQTT broker under
+    /// `<topic_prefix>/<status>`, so subscribers can filter by status with
+    /// topic wildcards.
+    Mqtt {
+        /// Address of the MQTT broker.
+        #[serde(deserialize_with = "deserialize_resolvable_socket_addr")]
+        broker_address: SocketAddr,
+        /// Prefix prepended to the slot status to form the publish topic.
+        topic_prefix: String,
+        /// MQTT quality of service level (0, 1, or 2).
+        #[serde(default = "TransportConfig::default_qos")]
+        qos: u8,
+    },
+}
+
+impl TransportConfig {
+    fn default_broadcast_capacity() -> usize {
+        1024
+    }
+
+    fn default_ping_interval_secs() -> u64 {
+        15
+    }
+
+    fn default_max_concurrent_streams() -> u32 {
+        100
+    }
+
+    fn default_idle_timeout_secs() -> u64 {
+        30
+    }
+
+    fn default_qos() -> u8 {
+        1
+    }
+}
+
+/// Smooths bursts of outgoing UDP datagrams: messages are buffered and
+/// flushed on a fixed tick instead of being written to the socket as soon as
+/// they arrive.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PacingConfig {
+    /// Maximum number of buffered messages flushed per target on each tick.
+    #[serde(deserialize_with = "deserialize_positive_usize")]
+    pub max_packets_per_interval: usize,
+    /// Tick length, in milliseconds, at which buffered messages are flushed.
+    #[serde(deserialize_with = "deserialize_positive_u64")]
+    pub interval_ms: u64,
+    /// Maximum number of messages held in the pacing buffer; once full, the
+    /// oldest buffered message is dropped to make room for the newest one.
+    #[serde(
+        default = "PacingConfig::default_buffer_capacity",
+        deserialize_with = "deserialize_positive_usize"
+    )]
+    pub buffer_capacity: usize,
+}
+
+impl PacingConfig {
+    fn default_buffer_capacity() -> usize {
+        256
+    }
 }
 
 fn deserialize_resolvable_socket_addr<'de, D>(deserializer: D) -> Result<SocketAddr, D::Error>
@@ -98,14 +210,50 @@ where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
+    resolve_socket_addr(&s).map_err(serde::de::Error::custom)
+}
+
+fn deserialize_resolvable_socket_addrs<'de, D>(deserializer: D) -> Result<Vec<SocketAddr>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|s| resolve_socket_addr(s).map_err(serde::de::Error::custom))
+        .collect()
+}
+
+fn deserialize_positive_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = u64::deserialize(deserializer)?;
+    if value == 0 {
+        return Err(serde::de::Error::custom("must be greater than zero"));
+    }
+    Ok(value)
+}
+
+fn deserialize_positive_usize<'de, D>(deserializer: D) -> Result<usize, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = usize::deserialize(deserializer)?;
+    if value == 0 {
+        return Err(serde::de::Error::custom("must be greater than zero"));
+    }
+    Ok(value)
+}
+
+fn resolve_socket_addr(s: &str) -> Result<SocketAddr, String> {
     if let Ok(addr) = s.parse::<SocketAddr>() {
         return Ok(addr);
     }
     // Try system resolver (/etc/hosts + DNS)
     s.to_socket_addrs()
-        .map_err(serde::de::Error::custom)?
+        .map_err(|e| e.to_string())?
         .next()
-        .ok_or_else(|| serde::de::Error::custom(format!("Failed to resolve address: {s}")))
+        .ok_or_else(|| format!("Failed to resolve address: {s}"))
 }
 
 #[cfg(test)]
@@ -126,8 +274,8 @@ mod tests {
                 "tokio": {{ "worker_threads": 8, "thread_name": "custom" }},
                 "broadcaster": {{
                     "bind_address": "127.0.0.1:8000",
-                    "target_address": "127.0.0.1:9000",
-                    "channel_capacity": 10
+                    "channel_capacity": 10,
+                    "transport": {{ "type": "udp", "target_addresses": ["127.0.0.1:9000"] }}
                 }}
             }}"#
         )
@@ -144,11 +292,21 @@ mod tests {
             cfg.broadcaster.bind_address,
             SocketAddr::from((Ipv4Addr::LOCALHOST, 8000))
         );
-        assert_eq!(
-            cfg.broadcaster.target_address,
-            SocketAddr::from((Ipv4Addr::LOCALHOST, 9000))
-        );
         assert_eq!(cfg.broadcaster.channel_capacity, 10);
+        assert_eq!(cfg.broadcaster.format, WireFormat::Json);
+        match cfg.broadcaster.transport {
+            TransportConfig::Udp {
+                target_addresses,
+                pacing,
+            } => {
+                assert_eq!(
+                    target_addresses,
+                    vec![SocketAddr::from((Ipv4Addr::LOCALHOST, 9000))]
+                );
+                assert!(pacing.is_none());
+            }
+            other => panic!("expected Udp transport, got {other:?}"),
+        }
     }
 
     #[test]
@@ -158,8 +316,8 @@ mod tests {
             "libpath": "/libtest.so",
             "broadcaster": {
                 "bind_address": "127.0.0.1:1000",
-                "target_address": "127.0.0.1:2000",
-                "channel_capacity": 1
+                "channel_capacity": 1,
+                "transport": { "type": "udp", "target_addresses": ["127.0.0.1:2000"] }
             }
         }"#;
 
@@ -169,6 +327,199 @@ mod tests {
         assert_eq!(cfg.tokio.thread_name, "tokio-worker");
     }
 
+    #[test]
+    fn test_load_from_str_udp_multiple_targets_with_pacing() {
+        let json = r#"
+        {
+            "libpath": "/libtest.so",
+            "broadcaster": {
+                "bind_address": "127.0.0.1:1000",
+                "channel_capacity": 1,
+                "transport": {
+                    "type": "udp",
+                    "target_addresses": ["127.0.0.1:2000", "127.0.0.1:2001"],
+                    "pacing": { "max_packets_per_interval": 5, "interval_ms": 10 }
+                }
+            }
+        }"#;
+
+        let cfg = Config::load_from_str(json).unwrap();
+        match cfg.broadcaster.transport {
+            TransportConfig::Udp {
+                target_addresses,
+                pacing,
+            } => {
+                assert_eq!(target_addresses.len(), 2);
+                let pacing = pacing.unwrap();
+                assert_eq!(pacing.max_packets_per_interval, 5);
+                assert_eq!(pacing.interval_ms, 10);
+                assert_eq!(pacing.buffer_capacity, 256);
+            }
+            other => panic!("expected Udp transport, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_load_from_str_grpc_transport_defaults() {
+        let json = r#"
+        {
+            "libpath": "/libtest.so",
+            "broadcaster": {
+                "bind_address": "127.0.0.1:1000",
+                "channel_capacity": 1,
+                "transport": { "type": "grpc" }
+            }
+        }"#;
+
+        let cfg = Config::load_from_str(json).unwrap();
+        match cfg.broadcaster.transport {
+            TransportConfig::Grpc {
+                broadcast_capacity,
+                ping_interval_secs,
+            } => {
+                assert_eq!(broadcast_capacity, 1024);
+                assert_eq!(ping_interval_secs, 15);
+            }
+            other => panic!("expected Grpc transport, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_load_from_str_quic_transport_defaults() {
+        let json = r#"
+        {
+            "libpath": "/libtest.so",
+            "broadcaster": {
+                "bind_address": "127.0.0.1:1000",
+                "channel_capacity": 1,
+                "transport": { "type": "quic" }
+            }
+        }"#;
+
+        let cfg = Config::load_from_str(json).unwrap();
+        match cfg.broadcaster.transport {
+            TransportConfig::Quic {
+                cert_path,
+                key_path,
+                max_concurrent_streams,
+                idle_timeout_secs,
+            } => {
+                assert_eq!(cert_path, None);
+                assert_eq!(key_path, None);
+                assert_eq!(max_concurrent_streams, 100);
+                assert_eq!(idle_timeout_secs, 30);
+            }
+            other => panic!("expected Quic transport, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_load_from_str_mqtt_transport() {
+        let json = r#"
+        {
+            "libpath": "/libtest.so",
+            "broadcaster": {
+                "bind_address": "127.0.0.1:1000",
+                "channel_capacity": 1,
+                "transport": {
+                    "type": "mqtt",
+                    "broker_address": "127.0.0.1:1883",
+                    "topic_prefix": "solana/slots"
+                }
+            }
+        }"#;
+
+        let cfg = Config::load_from_str(json).unwrap();
+        match cfg.broadcaster.transport {
+            TransportConfig::Mqtt {
+                broker_address,
+                topic_prefix,
+                qos,
+            } => {
+                assert_eq!(broker_address, SocketAddr::from((Ipv4Addr::LOCALHOST, 1883)));
+                assert_eq!(topic_prefix, "solana/slots");
+                assert_eq!(qos, 1);
+            }
+            other => panic!("expected Mqtt transport, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_load_from_str_explicit_compact_format() {
+        let json = r#"
+        {
+            "libpath": "/libtest.so",
+            "broadcaster": {
+                "bind_address": "127.0.0.1:1000",
+                "channel_capacity": 1,
+                "transport": { "type": "udp", "target_addresses": ["127.0.0.1:2000"] },
+                "format": "compact"
+            }
+        }"#;
+
+        let cfg = Config::load_from_str(json).unwrap();
+        assert_eq!(cfg.broadcaster.format, WireFormat::Compact);
+    }
+
+    #[test]
+    fn test_load_from_str_rejects_zero_pacing_interval() {
+        let json = r#"
+        {
+            "libpath": "/libtest.so",
+            "broadcaster": {
+                "bind_address": "127.0.0.1:1000",
+                "channel_capacity": 1,
+                "transport": {
+                    "type": "udp",
+                    "target_addresses": ["127.0.0.1:2000"],
+                    "pacing": { "max_packets_per_interval": 5, "interval_ms": 0 }
+                }
+            }
+        }"#;
+
+        let err = Config::load_from_str(json).unwrap_err();
+        match err {
+            GeyserPluginError::ConfigFileReadError { msg } => {
+                assert!(msg.contains("greater than zero"));
+            }
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[test]
+    fn test_load_from_str_rejects_zero_pacing_buffer_capacity() {
+        let json = r#"
+        {
+            "libpath": "/libtest.so",
+            "broadcaster": {
+                "bind_address": "127.0.0.1:1000",
+                "channel_capacity": 1,
+                "transport": {
+                    "type": "udp",
+                    "target_addresses": ["127.0.0.1:2000"],
+                    "pacing": { "max_packets_per_interval": 5, "interval_ms": 10, "buffer_capacity": 0 }
+                }
+            }
+        }"#;
+
+        assert!(Config::load_from_str(json).is_err());
+    }
+
+    #[test]
+    fn test_load_from_str_rejects_zero_grpc_ping_interval() {
+        let json = r#"
+        {
+            "libpath": "/libtest.so",
+            "broadcaster": {
+                "bind_address": "127.0.0.1:1000",
+                "channel_capacity": 1,
+                "transport": { "type": "grpc", "ping_interval_secs": 0 }
+            }
+        }"#;
+
+        assert!(Config::load_from_str(json).is_err());
+    }
+
     #[test]
     fn test_invalid_json_returns_error() {
         let json = r#"{ "libpath": "x", "broadcaster": {} }"#;
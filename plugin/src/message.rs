@@ -29,17 +29,167 @@ impl SlotMessage {
             created_at: timestamp(),
         }
     }
+
+    pub fn encode(&self, format: WireFormat) -> anyhow::Result<Vec<u8>> {
+        match format {
+            WireFormat::Json => Ok(serde_json::to_vec(self)?),
+            WireFormat::Bincode => Ok(bincode::serialize(self)?),
+            WireFormat::Compact => Ok(self.encode_compact()),
+        }
+    }
+
+    pub fn decode(data: &[u8], format: WireFormat) -> anyhow::Result<Self> {
+        match format {
+            WireFormat::Json => Ok(serde_json::from_slice(data)?),
+            WireFormat::Bincode => Ok(bincode::deserialize(data)?),
+            WireFormat::Compact => Self::decode_compact(data),
+        }
+    }
+
+    /// Fixed-layout little-endian binary record: 8-byte slot, 1-byte flag for
+    /// parent presence + 8-byte parent, 1-byte status discriminant, 8-byte
+    /// `created_at`, and a length-prefixed UTF-8 `dead_error` present only
+    /// when the status is `Dead`.
+    fn encode_compact(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(27);
+        buf.extend_from_slice(&self.slot.to_le_bytes());
+        buf.push(self.parent.is_some() as u8);
+        buf.extend_from_slice(&self.parent.unwrap_or(0).to_le_bytes());
+        buf.push(slot_status_as_str::discriminant(&self.status));
+        buf.extend_from_slice(&self.created_at.to_le_bytes());
+        if matches!(self.status, GeyserSlotStatus::Dead(_)) {
+            let bytes = self.dead_error.as_deref().unwrap_or_default().as_bytes();
+            buf.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            buf.extend_from_slice(bytes);
+        }
+        buf
+    }
+
+    fn decode_compact(data: &[u8]) -> anyhow::Result<Self> {
+        let mut cursor = CompactCursor::new(data);
+        let slot = cursor.read_u64()?;
+        let has_parent = cursor.read_u8()? != 0;
+        let parent_value = cursor.read_u64()?;
+        let parent = has_parent.then_some(parent_value);
+        let discriminant = cursor.read_u8()?;
+        let created_at = cursor.read_u64()?;
+
+        let mut status = slot_status_as_str::from_discriminant(discriminant)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        let dead_error = if matches!(status, GeyserSlotStatus::Dead(_)) {
+            let len = cursor.read_u32()? as usize;
+            let error = String::from_utf8(cursor.read_bytes(len)?.to_vec())?;
+            status = GeyserSlotStatus::Dead(error.clone());
+            Some(error)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            slot,
+            parent,
+            status,
+            dead_error,
+            created_at,
+        })
+    }
+}
+
+/// Selects how `SlotMessage`s are serialized on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WireFormat {
+    Json,
+    Bincode,
+    Compact,
+}
+
+impl Default for WireFormat {
+    fn default() -> Self {
+        WireFormat::Json
+    }
+}
+
+/// Bounds-checked reader over a byte slice, used by `decode_compact` so a
+/// truncated record is reported as an error instead of panicking.
+struct CompactCursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> CompactCursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn read_bytes(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| anyhow::anyhow!("truncated compact SlotMessage"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> anyhow::Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> anyhow::Result<u32> {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    fn read_u64(&mut self) -> anyhow::Result<u64> {
+        Ok(u64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
 }
 
 pub mod slot_status_as_str {
     use super::*;
     use agave_geyser_plugin_interface::geyser_plugin_interface::SlotStatus;
 
+    /// The same string representation used for (de)serialization, exposed so
+    /// other code (e.g. MQTT topic routing) can stay in sync with it.
+    pub fn as_str(status: &SlotStatus) -> &'static str {
+        status.as_str()
+    }
+
+    /// 1-byte discriminant used by the `compact` wire format, in the same
+    /// variant order as `serialize`/`deserialize`.
+    pub fn discriminant(status: &SlotStatus) -> u8 {
+        match status {
+            SlotStatus::Processed => 0,
+            SlotStatus::Rooted => 1,
+            SlotStatus::Confirmed => 2,
+            SlotStatus::FirstShredReceived => 3,
+            SlotStatus::Completed => 4,
+            SlotStatus::CreatedBank => 5,
+            SlotStatus::Dead(_) => 6,
+        }
+    }
+
+    /// Inverse of `discriminant`. `Dead` is returned with an empty error
+    /// string; callers read the real error separately.
+    pub fn from_discriminant(discriminant: u8) -> Result<SlotStatus, String> {
+        match discriminant {
+            0 => Ok(SlotStatus::Processed),
+            1 => Ok(SlotStatus::Rooted),
+            2 => Ok(SlotStatus::Confirmed),
+            3 => Ok(SlotStatus::FirstShredReceived),
+            4 => Ok(SlotStatus::Completed),
+            5 => Ok(SlotStatus::CreatedBank),
+            6 => Ok(SlotStatus::Dead(String::new())),
+            other => Err(format!("unknown status discriminant: {other}")),
+        }
+    }
+
     pub fn serialize<S>(status: &SlotStatus, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(status.as_str())
+        serializer.serialize_str(as_str(status))
     }
 
     pub fn deserialize<'de, D>(deserializer: D) -> Result<SlotStatus, D::Error>
@@ -74,7 +224,7 @@ pub mod slot_status_as_str {
 #[cfg(test)]
 mod tests {
     use {
-        crate::message::SlotMessage,
+        crate::message::{SlotMessage, WireFormat},
         agave_geyser_plugin_interface::geyser_plugin_interface::SlotStatus,
         solana_time_utils::timestamp,
     };
@@ -110,4 +260,70 @@ mod tests {
         let decoded: SlotMessage = serde_json::from_str(&json).unwrap();
         assert_eq!(decoded, msg);
     }
+
+    #[test]
+    fn test_bincode_roundtrip() {
+        let msg = SlotMessage {
+            slot: 77,
+            parent: Some(70),
+            status: SlotStatus::Completed,
+            dead_error: None,
+            created_at: timestamp(),
+        };
+
+        let encoded = msg.encode(WireFormat::Bincode).unwrap();
+        let decoded = SlotMessage::decode(&encoded, WireFormat::Bincode).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn test_compact_roundtrip_non_dead() {
+        let msg = SlotMessage {
+            slot: 77,
+            parent: Some(70),
+            status: SlotStatus::Completed,
+            dead_error: None,
+            created_at: timestamp(),
+        };
+
+        let encoded = msg.encode(WireFormat::Compact).unwrap();
+        let decoded = SlotMessage::decode(&encoded, WireFormat::Compact).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn test_compact_roundtrip_without_parent() {
+        let msg = SlotMessage {
+            slot: 1,
+            parent: None,
+            status: SlotStatus::Processed,
+            dead_error: None,
+            created_at: timestamp(),
+        };
+
+        let encoded = msg.encode(WireFormat::Compact).unwrap();
+        let decoded = SlotMessage::decode(&encoded, WireFormat::Compact).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn test_compact_roundtrip_dead_status_carries_error() {
+        let msg = SlotMessage {
+            slot: 99,
+            parent: Some(98),
+            status: SlotStatus::Dead("bank hash mismatch".to_string()),
+            dead_error: Some("bank hash mismatch".to_string()),
+            created_at: timestamp(),
+        };
+
+        let encoded = msg.encode(WireFormat::Compact).unwrap();
+        let decoded = SlotMessage::decode(&encoded, WireFormat::Compact).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn test_compact_decode_rejects_truncated_input() {
+        let err = SlotMessage::decode(&[0u8; 4], WireFormat::Compact).unwrap_err();
+        assert!(err.to_string().contains("truncated"));
+    }
 }